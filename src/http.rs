@@ -0,0 +1,237 @@
+use std::{
+  collections::HashMap,
+  io::{self, BufRead},
+};
+
+/// The HTTP method of a parsed [`Request`].
+///
+/// Only the methods the server currently cares about get their own variant;
+/// anything else still parses, it just lands in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Method {
+  Get,
+  Post,
+  Put,
+  Delete,
+  Head,
+  Other(String),
+}
+
+impl Method {
+  fn parse(s: &str) -> Method {
+    match s {
+      "GET" => Method::Get,
+      "POST" => Method::Post,
+      "PUT" => Method::Put,
+      "DELETE" => Method::Delete,
+      "HEAD" => Method::Head,
+      other => Method::Other(other.to_string()),
+    }
+  }
+}
+
+/// A parsed HTTP request: the request line, headers, and (if a
+/// `Content-Length` header is present) the body.
+#[derive(Debug)]
+pub struct Request {
+  pub method: Method,
+  pub path: String,
+  pub version: String,
+  pub headers: HashMap<String, String>,
+  pub body: Vec<u8>,
+}
+
+impl Request {
+  /// Reads and parses a single HTTP request from `reader`.
+  ///
+  /// Reads the request line, then header lines until the blank CRLF line
+  /// that ends the header block, splitting each header on the first `:`.
+  /// If a `Content-Length` header is present, reads exactly that many bytes
+  /// from `reader` as the body.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the stream ends before a request line or the
+  /// header block is complete, or if the body can't be read in full.
+  pub fn parse<R: BufRead>(reader: &mut R) -> io::Result<Request> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    if request_line.is_empty() {
+      return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed before request line"));
+    }
+
+    let mut parts = request_line.trim_end().splitn(3, ' ');
+    let method = Method::parse(parts.next().unwrap_or(""));
+    let path = parts.next().unwrap_or("/").to_string();
+    let version = parts.next().unwrap_or("HTTP/1.1").to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+      let mut line = String::new();
+      let bytes_read = reader.read_line(&mut line)?;
+      if bytes_read == 0 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed before the header block was complete"));
+      }
+
+      let line = line.trim_end();
+      if line.is_empty() {
+        break;
+      }
+
+      if let Some((name, value)) = line.split_once(':') {
+        headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+      }
+    }
+
+    let body = match headers.get("content-length").and_then(|len| len.parse::<usize>().ok()) {
+      Some(len) => {
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body)?;
+        body
+      }
+      None => Vec::new(),
+    };
+
+    Ok(Request { method, path, version, headers, body })
+  }
+}
+
+/// An HTTP response: a status line, headers, and a body.
+#[derive(Debug)]
+pub struct Response {
+  pub status_line: String,
+  pub headers: HashMap<String, String>,
+  pub body: Vec<u8>,
+}
+
+impl Response {
+  /// Builds a response from a status line (e.g. `"HTTP/1.1 200 OK"`) and a
+  /// body. A `Content-Length` header matching the body is added automatically.
+  pub fn new(status_line: impl Into<String>, body: impl Into<Vec<u8>>) -> Response {
+    let body = body.into();
+    let mut headers = HashMap::new();
+    headers.insert("Content-Length".to_string(), body.len().to_string());
+
+    Response { status_line: status_line.into(), headers, body }
+  }
+
+  /// Serializes the response into the bytes that go on the wire.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut head = format!("{}\r\n", self.status_line);
+    for (name, value) in &self.headers {
+      head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+
+    let mut bytes = head.into_bytes();
+    bytes.extend_from_slice(&self.body);
+    bytes
+  }
+}
+
+type Handler = Box<dyn Fn(&Request) -> Response + Send + Sync>;
+
+/// Maps `(Method, path)` pairs to handlers, with a built-in 404 fallback.
+pub struct Router {
+  routes: HashMap<(Method, String), Handler>,
+}
+
+impl Router {
+  /// Creates an empty router.
+  pub fn new() -> Router {
+    Router { routes: HashMap::new() }
+  }
+
+  /// Registers a handler for `method` and `path`.
+  pub fn add<F>(&mut self, method: Method, path: &str, handler: F)
+  where
+    F: Fn(&Request) -> Response + Send + Sync + 'static,
+  {
+    self.routes.insert((method, path.to_string()), Box::new(handler));
+  }
+
+  /// Dispatches `request` to the matching handler, or a `404 NOT FOUND`
+  /// response if no route matches.
+  pub fn route(&self, request: &Request) -> Response {
+    match self.routes.get(&(request.method.clone(), request.path.clone())) {
+      Some(handler) => handler(request),
+      None => {
+        let contents = std::fs::read_to_string("404.html").unwrap_or_default();
+        Response::new("HTTP/1.1 404 NOT FOUND", contents)
+      }
+    }
+  }
+}
+
+impl Default for Router {
+  fn default() -> Router {
+    Router::new()
+  }
+}
+
+#[cfg(test)]
+/// Tests for `Request` parsing and `Router` dispatch.
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Tests that the request line and headers are parsed into their respective fields, and that
+    /// a request with no `Content-Length` gets an empty body.
+    #[test]
+    fn parses_request_line_and_headers() {
+        let raw = "GET /hello HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\n";
+        let mut reader = Cursor::new(raw);
+
+        let request = Request::parse(&mut reader).unwrap();
+
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(request.path, "/hello");
+        assert_eq!(request.version, "HTTP/1.1");
+        assert_eq!(request.headers.get("host").unwrap(), "localhost");
+        assert!(request.body.is_empty());
+    }
+
+    /// Tests that exactly `Content-Length` bytes are read off the reader as the body.
+    #[test]
+    fn reads_body_of_content_length() {
+        let raw = "POST /submit HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello";
+        let mut reader = Cursor::new(raw);
+
+        let request = Request::parse(&mut reader).unwrap();
+
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.body, b"hello");
+    }
+
+    /// Tests that the router calls the handler registered for a matching `(Method, path)` pair.
+    #[test]
+    fn router_dispatches_to_matching_handler() {
+        let mut router = Router::new();
+        router.add(Method::Get, "/ping", |_req| Response::new("HTTP/1.1 200 OK", "pong"));
+
+        let request = Request {
+            method: Method::Get,
+            path: "/ping".to_string(),
+            version: "HTTP/1.1".to_string(),
+            headers: HashMap::new(),
+            body: Vec::new(),
+        };
+
+        let response = router.route(&request);
+
+        assert_eq!(response.status_line, "HTTP/1.1 200 OK");
+        assert_eq!(response.body, b"pong");
+    }
+
+    /// Tests that a connection closed mid-header-block is reported as `UnexpectedEof` rather than
+    /// silently parsed as a complete request with whatever headers happened to be read so far.
+    #[test]
+    fn errors_on_truncated_header_block() {
+        let raw = "GET /x HTTP/1.1\r\nHost: localhost\r\nX-Partial: yes";
+        let mut reader = Cursor::new(raw);
+
+        let err = Request::parse(&mut reader).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
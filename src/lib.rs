@@ -0,0 +1,695 @@
+use std::{
+  any::Any,
+  fmt, error,
+  panic::{self, AssertUnwindSafe},
+  sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    mpsc, Arc, Condvar, Mutex,
+  },
+  thread,
+  time::Duration,
+};
+
+pub mod http;
+
+/// A thread pool that manages a collection of worker threads.
+pub struct ThreadPool {
+  workers: Arc<Mutex<Vec<Worker>>>,
+  sender: Option<mpsc::Sender<Job>>,
+  requests_handled: Arc<AtomicUsize>,
+  supervisor_stopping: Arc<AtomicBool>,
+  supervisor: Option<thread::JoinHandle<()>>,
+  queue: Arc<QueueState>,
+  queue_limit: Option<usize>,
+}
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Tracks how many jobs are currently queued (submitted but not yet popped by a worker), plus
+/// the condvar `execute` waits on when a `queue_limit` is in effect and the queue is full.
+type QueueState = (Mutex<usize>, Condvar);
+
+/// The error [`ThreadPool::try_execute`] returns when the queue is at its `queue_limit`.
+#[derive(Debug)]
+pub struct QueueFull;
+
+impl fmt::Display for QueueFull {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "thread pool job queue is at capacity")
+  }
+}
+
+impl error::Error for QueueFull {}
+
+/// A handle to a job submitted via [`ThreadPool::submit`], letting the caller collect its result.
+pub struct JobHandle<T> {
+  receiver: mpsc::Receiver<T>,
+}
+
+impl<T> JobHandle<T> {
+  /// Blocks until the job's result arrives and returns it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the worker running the job never sends a result — this happens if the job panics,
+  /// since the result sender is then dropped without being used.
+  pub fn join(self) -> T {
+    self
+      .receiver
+      .recv()
+      .expect("job panicked before producing a result")
+  }
+}
+
+
+impl ThreadPool {
+  /// Create a new ThreadPool with an unbounded job queue.
+  ///
+  /// The size is the number of threads in the pool.
+  ///
+  /// # Panics
+  ///
+  /// The `new` function will panic if the size is zero.
+  pub fn new(size: usize) -> ThreadPool {
+    Self::build(size, None)
+  }
+
+  /// Create a new ThreadPool whose job queue holds at most `queue_limit` outstanding jobs.
+  ///
+  /// Once that many jobs are queued, [`execute`](ThreadPool::execute) blocks until a worker pops
+  /// one, and [`try_execute`](ThreadPool::try_execute) returns `Err(QueueFull)` instead of
+  /// queuing. This gives callers real backpressure instead of letting jobs pile up unbounded.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `size` is zero.
+  pub fn with_capacity(size: usize, queue_limit: usize) -> ThreadPool {
+    Self::build(size, Some(queue_limit))
+  }
+
+  fn build(size: usize, queue_limit: Option<usize>) -> ThreadPool {
+    assert!(size > 0);
+
+    let (sender, receiver) = mpsc::channel();
+
+    let receiver = Arc::new(Mutex::new(receiver));
+    let requests_handled = Arc::new(AtomicUsize::new(0));
+    let queue = Arc::new((Mutex::new(0), Condvar::new()));
+    let (respawn_sender, respawn_receiver) = mpsc::channel();
+
+    let mut initial_workers = Vec::with_capacity(size);
+
+    for id in 0..size {
+        initial_workers.push(Worker::new(
+            id,
+            Arc::clone(&receiver),
+            Arc::clone(&requests_handled),
+            Arc::clone(&queue),
+            respawn_sender.clone(),
+        ));
+    }
+
+    let workers = Arc::new(Mutex::new(initial_workers));
+    let supervisor_stopping = Arc::new(AtomicBool::new(false));
+    let supervisor = Worker::spawn_supervisor(
+        Arc::clone(&workers),
+        Arc::clone(&receiver),
+        Arc::clone(&requests_handled),
+        Arc::clone(&queue),
+        respawn_sender,
+        respawn_receiver,
+        Arc::clone(&supervisor_stopping),
+    );
+
+    ThreadPool {
+      workers,
+      sender: Some(sender),
+      requests_handled,
+      supervisor_stopping,
+      supervisor: Some(supervisor),
+      queue,
+      queue_limit,
+    }
+  }
+
+  /// Executes a job in the thread pool.
+  ///
+  /// Takes a closure with `FnOnce + Send + 'static` traits, sends it to a worker for execution.
+  /// A panicking job is caught and logged by the worker that runs it (see [`Worker::new`]); it
+  /// still counts toward [`requests_handled`](ThreadPool::requests_handled).
+  ///
+  /// If the pool was built with [`with_capacity`](ThreadPool::with_capacity) and the queue is
+  /// already at its limit, this blocks until a worker pops a job and frees up a slot. Use
+  /// [`try_execute`](ThreadPool::try_execute) for a non-blocking alternative.
+  ///
+  /// # Arguments
+  ///
+  /// * `f` - The closure to execute, no arguments, returns nothing.
+  ///
+  /// # Panics
+  ///
+  /// Panics if sending the job to a worker fails.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use hello::ThreadPool;
+  ///
+  /// let pool = ThreadPool::new(4);
+  /// pool.execute(|| println!("Job executed by worker."));
+  /// ```
+  pub fn execute<F>(&self, f: F)
+  where
+      F: FnOnce() + Send + 'static,
+  {
+      self.wait_for_queue_slot();
+      self.send_job(Box::new(f));
+  }
+
+  /// Like [`execute`](ThreadPool::execute), but never blocks: if the queue is already at its
+  /// `queue_limit`, returns `Err(QueueFull)` instead of waiting for room. Pools created with
+  /// [`new`](ThreadPool::new) have no limit, so this always succeeds for them.
+  ///
+  /// # Panics
+  ///
+  /// Panics if sending the job to a worker fails.
+  pub fn try_execute<F>(&self, f: F) -> Result<(), QueueFull>
+  where
+      F: FnOnce() + Send + 'static,
+  {
+      if !self.reserve_queue_slot() {
+          return Err(QueueFull);
+      }
+
+      self.send_job(Box::new(f));
+      Ok(())
+  }
+
+  /// Sends an already-boxed job to a worker. Callers must have already reserved a queue slot for
+  /// it via [`wait_for_queue_slot`](ThreadPool::wait_for_queue_slot) or
+  /// [`reserve_queue_slot`](ThreadPool::reserve_queue_slot).
+  fn send_job(&self, job: Job) {
+      self.sender.as_ref().unwrap().send(job).unwrap();
+  }
+
+  /// Reserves a queue slot without blocking. Returns `false` if `queue_limit` is set and the
+  /// queue is already full; always returns `true` when there is no limit.
+  fn reserve_queue_slot(&self) -> bool {
+      let Some(limit) = self.queue_limit else {
+          return true;
+      };
+
+      let (count, _) = &*self.queue;
+      let mut count = count.lock().unwrap();
+      if *count >= limit {
+          return false;
+      }
+
+      *count += 1;
+      true
+  }
+
+  /// Reserves a queue slot, blocking on the queue's condvar while `queue_limit` is set and the
+  /// queue is full.
+  fn wait_for_queue_slot(&self) {
+      let Some(limit) = self.queue_limit else {
+          return;
+      };
+
+      let (count, full_condvar) = &*self.queue;
+      let mut count = count.lock().unwrap();
+      while *count >= limit {
+          count = full_condvar.wait(count).unwrap();
+      }
+
+      *count += 1;
+  }
+
+  /// Submits a job that computes a result and returns a [`JobHandle`] to collect it.
+  ///
+  /// Internally this still boxes the work into the same `Job` type `execute` uses: the closure
+  /// captures a fresh one-shot `mpsc` sender and sends its return value down it once `f` returns,
+  /// with the paired receiver handed back inside the `JobHandle`.
+  ///
+  /// # Arguments
+  ///
+  /// * `f` - The closure to run; its return value is delivered through the returned handle.
+  ///
+  /// # Panics
+  ///
+  /// Panics if sending the job to a worker fails.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use hello::ThreadPool;
+  ///
+  /// let pool = ThreadPool::new(4);
+  /// let handle = pool.submit(|| 2 + 2);
+  /// assert_eq!(handle.join(), 4);
+  /// ```
+  pub fn submit<F, T>(&self, f: F) -> JobHandle<T>
+  where
+      F: FnOnce() -> T + Send + 'static,
+      T: Send + 'static,
+  {
+      let (result_sender, result_receiver) = mpsc::channel();
+
+      self.execute(move || {
+          let _ = result_sender.send(f());
+      });
+
+      JobHandle { receiver: result_receiver }
+  }
+
+  /// The number of jobs the pool has finished running so far, whether they
+  /// completed normally or panicked.
+  ///
+  /// Intended to drive a `max_requests` shutdown policy: once this reaches a
+  /// caller-chosen limit, stop accepting new work and call
+  /// [`shutdown`](ThreadPool::shutdown).
+  pub fn requests_handled(&self) -> usize {
+      self.requests_handled.load(Ordering::SeqCst)
+  }
+
+  /// Initiates an orderly shutdown of the pool.
+  ///
+  /// Drops the job sender so no more work can be queued, stops the supervisor thread that
+  /// respawns dead workers, then joins every worker's thread. The supervisor is stopped *before*
+  /// workers are joined, and joining itself loops until a full pass finds nothing left to join:
+  /// if a respawn was already in flight when `shutdown` was called, the supervisor finishes
+  /// inserting that fresh `Worker` before its own thread exits, so the re-check loop still picks
+  /// it up instead of leaving it unjoined. Jobs already queued when `shutdown` is called are
+  /// still run to completion; only jobs submitted after this call are rejected (the `sender` is
+  /// `None`, so a subsequent `execute` would panic on `unwrap`).
+  ///
+  /// Safe to call more than once, and safe to let the pool simply drop
+  /// instead — `Drop` performs the same steps.
+  pub fn shutdown(&mut self) {
+      drop(self.sender.take());
+
+      self.supervisor_stopping.store(true, Ordering::SeqCst);
+      if let Some(supervisor) = self.supervisor.take() {
+          supervisor.join().unwrap();
+      }
+
+      loop {
+          let next = {
+              let mut workers = self.workers.lock().unwrap();
+              workers
+                  .iter_mut()
+                  .find_map(|worker| worker.thread.take().map(|thread| (worker.id, thread)))
+          };
+
+          match next {
+              Some((id, thread)) => {
+                  println!("Shutting down worker {id}");
+                  thread.join().unwrap();
+              }
+              None => break,
+          }
+      }
+  }
+}
+
+/// Implements the `Drop` trait for the `ThreadPool` struct.
+///
+/// When an instance of `ThreadPool` goes out of scope, this `Drop` implementation is called.
+/// It shuts down all the worker threads in the thread pool by joining them.
+/// It also drops the sender channel, preventing any further tasks from being submitted to the thread pool.
+impl Drop for ThreadPool {
+  fn drop(&mut self) {
+    self.shutdown();
+  }
+}
+
+/// Represents a worker that executes jobs received through a channel.
+/// Each worker runs in its own thread.
+struct Worker {
+  id: usize,
+  thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+  /// Creates a new `Worker` instance.
+  ///
+  /// Each job is run inside `catch_unwind`, so a panicking handler can't take the worker's
+  /// thread down with it: the panic is logged with the worker's id and the worker loops around
+  /// for the next job. Locking the shared receiver recovers from a poisoned mutex instead of
+  /// propagating it, for the same reason. The whole receive loop is itself wrapped in an outer
+  /// `catch_unwind` as a safety net for anything else that escapes that first layer — if that
+  /// happens the thread does end, and the worker reports its id on `respawn_sender` so the
+  /// pool's supervisor thread can bring a fresh `Worker` with the same id back up.
+  ///
+  /// # Arguments
+  ///
+  /// * `id` - The unique identifier for the worker.
+  /// * `receiver` - The shared receiver for receiving jobs through a channel.
+  /// * `requests_handled` - Shared counter incremented once per job, panicked or not.
+  /// * `queue` - Shared outstanding-job count; decremented and its condvar notified as soon as a job is popped, freeing a slot for a blocked `execute`.
+  /// * `respawn_sender` - Used to ask the supervisor for a replacement if this worker's thread dies anyway.
+  ///
+  /// # Returns
+  ///
+  /// A new `Worker` instance.
+  fn new(
+    id: usize,
+    receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
+    requests_handled: Arc<AtomicUsize>,
+    queue: Arc<QueueState>,
+    respawn_sender: mpsc::Sender<usize>,
+  ) -> Worker {
+    let thread = thread::spawn(move || {
+      let outcome = panic::catch_unwind(AssertUnwindSafe(|| loop {
+        // Recover from a poisoned lock rather than propagating it: an ordinary job panic is
+        // always caught by the inner `catch_unwind` below before it can unwind through here, so
+        // the only way this lock is ever poisoned is some other bug entirely. Panicking on that
+        // would make every respawned worker poison the lock again and trigger another respawn,
+        // forever — recovering lets the pool actually self-heal instead of spin-looping.
+        let message = receiver.lock().unwrap_or_else(|e| e.into_inner()).recv();
+
+        match message {
+          Ok(job) => {
+            println!("Worker {id} got a job; executing.");
+
+            {
+              let (count, full_condvar) = &*queue;
+              let mut count = count.lock().unwrap();
+              *count = count.saturating_sub(1);
+              full_condvar.notify_one();
+            }
+
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(job)) {
+              eprintln!("Worker {id} job panicked: {}", panic_payload_message(&payload));
+            }
+
+            requests_handled.fetch_add(1, Ordering::SeqCst);
+          }
+          Err(_) => {
+            println!("Worker {id} disconnected; shutting down.");
+            break;
+          }
+        }
+      }));
+
+      if outcome.is_err() {
+        eprintln!("Worker {id} terminated unexpectedly; requesting a respawn.");
+        let _ = respawn_sender.send(id);
+      }
+    });
+
+    Worker {
+      id,
+      thread: Some(thread),
+    }
+  }
+
+  /// Spawns the supervisor thread that watches `respawn_receiver` for worker ids reported dead
+  /// by [`Worker::new`] and replaces them in `workers` with a fresh `Worker` sharing the same id
+  /// and the pool's `receiver`. Polls on a short timeout rather than blocking on `recv` so it can
+  /// also notice `stopping` and exit once [`ThreadPool::shutdown`] sets it, before the workers
+  /// are joined.
+  fn spawn_supervisor(
+    workers: Arc<Mutex<Vec<Worker>>>,
+    receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
+    requests_handled: Arc<AtomicUsize>,
+    queue: Arc<QueueState>,
+    respawn_sender: mpsc::Sender<usize>,
+    respawn_receiver: mpsc::Receiver<usize>,
+    stopping: Arc<AtomicBool>,
+  ) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+      while !stopping.load(Ordering::SeqCst) {
+        if let Ok(id) = respawn_receiver.recv_timeout(Duration::from_millis(100)) {
+          println!("Respawning worker {id}.");
+
+          let fresh = Worker::new(
+            id,
+            Arc::clone(&receiver),
+            Arc::clone(&requests_handled),
+            Arc::clone(&queue),
+            respawn_sender.clone(),
+          );
+
+          let mut workers = workers.lock().unwrap();
+          if let Some(slot) = workers.iter_mut().find(|worker| worker.id == id) {
+            *slot = fresh;
+          }
+        }
+      }
+    })
+  }
+}
+
+/// Turns a `catch_unwind` payload into a human-readable message, falling back to a generic
+/// description for payloads that aren't a `&str` or `String` (the two types `panic!` produces).
+fn panic_payload_message(payload: &Box<dyn Any + Send>) -> String {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message.to_string()
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message.clone()
+  } else {
+    "non-string panic payload".to_string()
+  }
+}
+
+#[cfg(test)]
+/// Tests for the ThreadPool implementation.
+///
+/// These tests ensure that the ThreadPool behaves
+mod tests {
+    use super::*;
+
+    /// Tests that a ThreadPool with a non-zero number of threads can be created successfully.
+    ///
+    /// This test verifies that the `new` function of the ThreadPool struct correctly initializes
+    /// the pool with the specified number of workers. It checks that the length of the `workers`
+    /// vector matches the number provided to `new`.
+    #[test]
+    fn thread_pool_creation_non_zero() {
+        let pool = ThreadPool::new(4);
+        assert_eq!(pool.workers.lock().unwrap().len(), 4);
+    }
+
+    /// Tests that creating a ThreadPool with zero threads causes a panic.
+    ///
+    /// This test ensures that the ThreadPool cannot be created with a size of zero, as it would
+    /// not make sense to have a thread pool with no threads. It expects a panic with a specific
+    /// message indicating an assertion failure.
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn thread_pool_creation_zero() {
+        ThreadPool::new(0);
+    }
+
+    /// Tests that jobs can be executed by the ThreadPool.
+    ///
+    /// This test checks the functionality of the `execute` method. It creates a ThreadPool and
+    /// uses it to execute two jobs that send messages through a channel. The test verifies that
+    /// both messages are received, indicating that both jobs were executed by the pool.
+    #[test]
+    fn thread_pool_execute_job() {
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        let pool = ThreadPool::new(2);
+        let (tx, rx) = mpsc::channel();
+
+        let tx_clone = tx.clone();
+        pool.execute(move || {
+            tx_clone.send(1).unwrap();
+        });
+
+        pool.execute(move || {
+            tx.send(2).unwrap();
+        });
+
+        // Allow some time for the jobs to be executed
+        thread::sleep(Duration::from_secs(1));
+
+        let mut results = vec![];
+        for _ in 0..2 {
+            results.push(rx.try_recv().unwrap());
+        }
+
+        assert!(results.contains(&1));
+        assert!(results.contains(&2));
+    }
+
+    /// Tests that `requests_handled` tracks completed jobs, not just submitted ones.
+    #[test]
+    fn thread_pool_tracks_requests_handled() {
+        use std::time::Duration;
+
+        let pool = ThreadPool::new(2);
+        assert_eq!(pool.requests_handled(), 0);
+
+        pool.execute(|| {});
+        pool.execute(|| {});
+
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(pool.requests_handled(), 2);
+    }
+
+    /// Tests that `shutdown` lets already-queued jobs finish before the worker threads join.
+    #[test]
+    fn thread_pool_shutdown_drains_queued_jobs() {
+        use std::sync::mpsc;
+
+        let mut pool = ThreadPool::new(1);
+        let (tx, rx) = mpsc::channel();
+
+        pool.execute(move || {
+            tx.send(42).unwrap();
+        });
+
+        pool.shutdown();
+
+        assert_eq!(rx.recv().unwrap(), 42);
+    }
+
+    /// Tests that a panicking job doesn't take its worker down: later jobs still run, and the
+    /// panicked job is still reflected in `requests_handled`.
+    #[test]
+    fn thread_pool_survives_panicking_job() {
+        use std::time::Duration;
+
+        let pool = ThreadPool::new(1);
+        let (tx, rx) = mpsc::channel();
+
+        pool.execute(|| panic!("boom"));
+
+        let tx_clone = tx.clone();
+        pool.execute(move || {
+            tx_clone.send("still alive").unwrap();
+        });
+
+        let message = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(message, "still alive");
+
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(pool.requests_handled(), 2);
+    }
+
+    /// Tests that `submit` runs the closure on the pool and delivers its return value.
+    #[test]
+    fn thread_pool_submit_returns_result() {
+        let pool = ThreadPool::new(2);
+
+        let handle = pool.submit(|| 2 + 2);
+
+        assert_eq!(handle.join(), 4);
+    }
+
+    /// Tests that a panicking `submit`ted job reports the failure through `join` rather than
+    /// hanging forever.
+    #[test]
+    #[should_panic(expected = "job panicked before producing a result")]
+    fn thread_pool_submit_panics_on_failed_job() {
+        let pool = ThreadPool::new(1);
+
+        let handle = pool.submit(|| -> i32 { panic!("boom") });
+
+        handle.join();
+    }
+
+    /// Tests that `try_execute` rejects work once the queue is at `queue_limit`, and that a
+    /// completed job frees up a slot for the next call.
+    #[test]
+    fn thread_pool_try_execute_rejects_when_queue_full() {
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        // One worker held busy by a blocking first job, so the second job stays queued.
+        let pool = ThreadPool::with_capacity(1, 1);
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        pool.try_execute(move || {
+            release_rx.recv().unwrap();
+        })
+        .unwrap();
+
+        thread::sleep(Duration::from_millis(50));
+
+        assert!(pool.try_execute(|| {}).is_ok());
+        assert!(matches!(pool.try_execute(|| {}), Err(QueueFull)));
+
+        release_tx.send(()).unwrap();
+
+        thread::sleep(Duration::from_millis(100));
+        assert!(pool.try_execute(|| {}).is_ok());
+    }
+
+    /// Tests that `execute` blocks for room instead of failing once the queue is full, and
+    /// unblocks as soon as a worker pops the job ahead of it.
+    #[test]
+    fn thread_pool_execute_blocks_when_queue_full() {
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        let pool = Arc::new(ThreadPool::with_capacity(1, 1));
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        pool.execute(move || {
+            release_rx.recv().unwrap();
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        pool.execute(|| {});
+
+        let (done_tx, done_rx) = mpsc::channel();
+        let blocked_pool = Arc::clone(&pool);
+        thread::spawn(move || {
+            blocked_pool.execute(|| {});
+            done_tx.send(()).unwrap();
+        });
+
+        // The queue is full, so the spawned `execute` call should still be blocked.
+        assert!(done_rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+        release_tx.send(()).unwrap();
+
+        // Freeing a slot should let the blocked `execute` call return.
+        done_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+    }
+
+    /// Tests that a worker recovers from a poisoned receiver mutex instead of propagating the
+    /// poison and dying: poisons the shared receiver's mutex the way an unrelated bug elsewhere
+    /// in the lock's critical section could, then checks the worker still picks up and runs the
+    /// next job rather than reporting a respawn.
+    #[test]
+    fn worker_recovers_from_poisoned_receiver_lock() {
+        use std::time::Duration;
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let requests_handled = Arc::new(AtomicUsize::new(0));
+        let queue = Arc::new((Mutex::new(0), Condvar::new()));
+        let (respawn_sender, respawn_receiver) = mpsc::channel();
+
+        let poisoner_receiver = Arc::clone(&receiver);
+        let _ = thread::spawn(move || {
+            let _guard = poisoner_receiver.lock().unwrap();
+            panic!("poison the receiver's mutex");
+        })
+        .join();
+
+        let worker = Worker::new(7, receiver, requests_handled, queue, respawn_sender);
+
+        let (tx, rx) = mpsc::channel();
+        sender
+            .send(Box::new(move || tx.send(()).unwrap()))
+            .unwrap();
+
+        rx.recv_timeout(Duration::from_secs(1))
+            .expect("worker should still run jobs after the lock was poisoned");
+
+        assert!(
+            respawn_receiver.try_recv().is_err(),
+            "recovering from the poison shouldn't have triggered a respawn"
+        );
+
+        drop(sender);
+        worker.thread.unwrap().join().unwrap();
+    }
+}
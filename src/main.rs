@@ -0,0 +1,148 @@
+use hello::http::{Method, Request, Response, Router};
+use hello::ThreadPool;
+use std::{
+    fs,
+    io::{prelude::*, BufReader, ErrorKind},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+/// Caps the number of connections the server accepts before shutting down, the
+/// same demonstration the book uses (`listener.incoming().take(2)`). `None`
+/// means run until Ctrl-C is pressed.
+const MAX_REQUESTS: Option<usize> = None;
+
+/// Caps how many jobs can be queued on the pool at once. Without this, a flood of connections
+/// (each `handle_connection` can sleep 5s on `/sleep`) would pile up unbounded jobs in memory.
+/// Once the queue is this full, the accept loop responds `503 SERVICE UNAVAILABLE` instead of
+/// queuing more work; see the `try_execute` call below.
+const QUEUE_LIMIT: usize = 16;
+
+/// Starts a TCP server on localhost:7878 to handle HTTP requests.
+///
+/// The server listens for incoming TCP connections on port 7878 of the local machine.
+/// Upon receiving a connection, it utilizes a ThreadPool with 4 threads to handle the connections concurrently.
+/// Each connection is processed by the `handle_connection` function, which dispatches the parsed
+/// request through a [`Router`].
+///
+/// The pool's job queue is capped at `QUEUE_LIMIT` via [`ThreadPool::with_capacity`]; once it's
+/// full, `try_execute` rejects the connection and the accept loop answers it with a `503` rather
+/// than queuing unbounded work behind it.
+///
+/// The accept loop polls rather than blocking forever, so it can notice a Ctrl-C signal or a
+/// `MAX_REQUESTS` cap and stop taking new connections. Jobs already queued on the pool still run
+/// to completion before [`ThreadPool::shutdown`] joins the worker threads.
+///
+/// # Panics
+///
+/// - Panics if the server fails to bind to the specified address or install the Ctrl-C handler.
+/// - Panics if accepting an incoming connection fails.
+fn main() {
+    let listener = TcpListener::bind("127.0.0.1:7878").unwrap();
+    listener.set_nonblocking(true).unwrap();
+
+    let mut pool = ThreadPool::with_capacity(4, QUEUE_LIMIT);
+    let router = Arc::new(build_router());
+
+    let shutdown_requested = Arc::new(AtomicBool::new(false));
+    let handler_flag = Arc::clone(&shutdown_requested);
+    ctrlc::set_handler(move || {
+        println!("Ctrl-C received, finishing in-flight requests and shutting down...");
+        handler_flag.store(true, Ordering::SeqCst);
+    })
+    .expect("failed to install Ctrl-C handler");
+
+    // Counts connections as they're accepted, not as their jobs finish, so `MAX_REQUESTS` bounds
+    // how many connections are let in - the same thing the book's `listener.incoming().take(2)`
+    // bounds - rather than how many happen to have completed by the time we next check.
+    let mut accepted = 0usize;
+
+    for stream in listener.incoming() {
+        if shutdown_requested.load(Ordering::SeqCst) {
+            break;
+        }
+
+        if MAX_REQUESTS.is_some_and(|max| accepted >= max) {
+            break;
+        }
+
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(50));
+                continue;
+            }
+            Err(e) => panic!("failed to accept connection: {e}"),
+        };
+
+        accepted += 1;
+
+        // A duplicate handle to the socket, so the connection can still be answered with a `503`
+        // if `try_execute` below rejects it; the original `stream` is what's handed to the job.
+        let responder = stream.try_clone().ok();
+
+        let router = Arc::clone(&router);
+        if pool
+            .try_execute(move || {
+                handle_connection(stream, &router);
+            })
+            .is_err()
+        {
+            eprintln!("job queue full; rejecting connection with 503");
+            if let Some(mut responder) = responder {
+                let response = Response::new("HTTP/1.1 503 SERVICE UNAVAILABLE", "Service temporarily unavailable, please retry.");
+                let _ = responder.write_all(&response.to_bytes());
+            }
+        }
+    }
+
+    pool.shutdown();
+}
+
+/// Builds the routing table for the server.
+///
+/// - `GET /` serves `hello.html`.
+/// - `GET /sleep` simulates a slow handler before serving `hello.html`.
+///
+/// Anything else falls through to the router's built-in 404 response.
+fn build_router() -> Router {
+    let mut router = Router::new();
+
+    router.add(Method::Get, "/", |_req| {
+        let contents = fs::read_to_string("hello.html").unwrap();
+        ok_response(contents)
+    });
+
+    router.add(Method::Get, "/sleep", |_req| {
+        thread::sleep(Duration::from_secs(5));
+        let contents = fs::read_to_string("hello.html").unwrap();
+        ok_response(contents)
+    });
+
+    router
+}
+
+/// Shorthand for a `200 OK` response carrying `contents` as the body.
+fn ok_response(contents: String) -> hello::http::Response {
+    hello::http::Response::new("HTTP/1.1 200 OK", contents)
+}
+
+/// Reads a full HTTP request off `stream`, dispatches it through `router`, and
+/// writes the resulting response back.
+///
+/// # Panics
+///
+/// - Panics if the request can't be parsed or the response can't be written.
+fn handle_connection(mut stream: TcpStream, router: &Router) {
+    let mut buf_reader = BufReader::new(&mut stream);
+    let request = Request::parse(&mut buf_reader).unwrap();
+
+    let response = router.route(&request);
+
+    stream.write_all(&response.to_bytes()).unwrap();
+}